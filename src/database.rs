@@ -1,18 +1,29 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::thread::JoinHandle;
 use std::thread::spawn;
+use std::path::Path;
 use std::path::PathBuf;
 
 use async_channel::Sender;
 use async_channel::Receiver;
 use async_channel::unbounded;
 
-use rusqlite::types::Value;
 use rusqlite::Connection;
+use rusqlite::OpenFlags;
 use rusqlite::params_from_iter;
-use rusqlite::ParamsFromIter;
+use rusqlite::ToSql;
+use rusqlite::TransactionBehavior;
 use rusqlite::types::ValueRef;
+use rusqlite::blob::ZeroBlob;
 
+use super::error::GenericError;
 use super::error::ResonateError;
+use super::from_row::FromRow;
+use super::from_row::RowView;
 
 #[derive(Debug)]
 pub enum ItemStream {
@@ -21,36 +32,97 @@ pub enum ItemStream {
     End
 }
 
+/// Type-erased result of decoding one row into the caller's target type on the
+/// worker thread. `Any` lets the result cross the channel without making
+/// `DatabaseTask` generic over every possible `T`; the caller downcasts it back
+/// to the concrete type it asked [`DataLink::query_as`]/[`DataLink::query_stream_as`]
+/// for.
+pub enum TypedItemStream {
+    Value(Box<dyn Any + Send>),
+    Error,
+    End
+}
+
+/// Decodes one row into the caller's target type, on the worker thread, before the
+/// result crosses the channel. Built from `T::from_row` by [`DataLink::query_as`]/
+/// [`DataLink::query_stream_as`] and boxed so `DatabaseTask::TypedQuery` doesn't need
+/// to be generic over `T`.
+type RowDecoder = Box<dyn Fn(&RowView) -> Result<Box<dyn Any + Send>, ResonateError> + Send>;
+
 pub enum InsertMessage {
     Success(usize),
     Error
 }
 
+/// A query string that is either a `&'static str` literal or one built at
+/// runtime, so callers are no longer limited to statically known SQL.
+pub type Query = Cow<'static, str>;
+
 pub enum DatabaseTask {
-    Execute(&'static str, DatabaseParams),
-    WaitExecute(&'static str, DatabaseParams, Sender<()>),
-    Insert(&'static str, DatabaseParams, Sender<InsertMessage>),
-    Query(&'static str, DatabaseParams, Sender<ItemStream>),
+    Execute(Query, DatabaseParams),
+    WaitExecute(Query, DatabaseParams, Sender<()>),
+    Insert(Query, DatabaseParams, Sender<InsertMessage>),
+    Query(Query, DatabaseParams, Sender<ItemStream>),
+    /// Like `Query`, but decodes each row into the caller's target type via
+    /// `decoder` on the worker thread, before the result crosses the channel.
+    TypedQuery(Query, DatabaseParams, RowDecoder, Sender<TypedItemStream>),
+    /// Runs every statement in order inside a single transaction, rolling back
+    /// the whole unit if any statement fails. The `bool` requests `BEGIN IMMEDIATE`
+    /// so the write lock is acquired up front instead of on first write.
+    Transaction(Vec<(Query, DatabaseParams)>, bool, Sender<Result<Vec<usize>, ()>>),
+    /// Runs the first page of an FTS5 `MATCH` query against `table` and hands back
+    /// the rows plus the id of the cursor that tracks pagination state on the worker.
+    Search(&'static str, String, usize, Sender<(CursorId, Vec<Vec<DatabaseParam>>)>),
+    /// Fetches the next page for a cursor previously returned by `Search`. `None`
+    /// once the cursor is exhausted (and the worker drops its state).
+    SearchNext(CursorId, Sender<Option<Vec<Vec<DatabaseParam>>>>),
+    /// Evicts a cursor's pagination state without fetching another page, sent when
+    /// a [`SearchCursor`] is dropped before it runs out of pages, so abandoned
+    /// searches don't leak entries in the worker's cursor table.
+    SearchDrop(CursorId),
 }
 
+/// Opaque handle identifying a live FTS5 search cursor on the worker thread.
+pub type CursorId = u64;
+
 #[derive(Clone, Debug)]
 pub enum DatabaseParam {
     String(String),
     Usize(usize),
     Null,
     F64(f64),
+    Blob(Vec<u8>),
+    I64(i64),
+    Bool(bool),
+    /// Binds a pre-allocated, zero-filled blob of the given size, for later
+    /// incremental writes via `Connection::blob_open`.
+    ZeroBlob(usize),
 }
 
 impl DatabaseParam {
-    fn to_sql(&self) -> Value {
+    fn to_sql(&self) -> rusqlite::Result<Box<dyn ToSql>> {
         match self {
-            Self::String(v) => Value::from(v.to_owned()),
-            Self::Usize(v) => Value::from(*v as isize),
-            Self::Null => Value::Null,
-            Self::F64(v) => Value::Real(*v),
+            Self::String(v) => Ok(Box::new(v.to_owned())),
+            Self::Usize(v) => Ok(Box::new(*v as isize)),
+            Self::Null => Ok(Box::new(Option::<isize>::None)),
+            Self::F64(v) => Ok(Box::new(*v)),
+            Self::Blob(v) => Ok(Box::new(v.to_owned())),
+            Self::I64(v) => Ok(Box::new(*v)),
+            Self::Bool(v) => Ok(Box::new(*v)),
+            Self::ZeroBlob(size) => {
+                if *size > i32::MAX as usize {
+                    // Bind failures surface through the same `rusqlite::Result` path as
+                    // every other statement error, instead of panicking and taking the
+                    // worker thread (and the whole `Database`) down with it.
+                    return Err(rusqlite::Error::ToSqlConversionFailure(
+                        format!("ZeroBlob size {size} exceeds SQLite's 2 GiB (i32) limit").into()
+                    ));
+                }
+                Ok(Box::new(ZeroBlob(*size as i32)))
+            },
         }
     }
-    
+
     pub fn usize(&self) -> usize {
         if let Self::Usize(v) = self { return *v; }
         panic!("Attempted to get a USIZE from a non-usize value");
@@ -60,45 +132,108 @@ impl DatabaseParam {
         if let Self::String(v) = self { return v.clone(); }
         panic!("Attempted to get a STRING from a non-string value");
     }
+
+    pub fn blob(&self) -> Vec<u8> {
+        if let Self::Blob(v) = self { return v.clone(); }
+        panic!("Attempted to get a BLOB from a non-blob value");
+    }
+
+    pub fn i64(&self) -> i64 {
+        if let Self::I64(v) = self { return *v; }
+        panic!("Attempted to get an I64 from a non-i64 value");
+    }
+
+    pub fn bool(&self) -> bool {
+        if let Self::Bool(v) = self { return *v; }
+        panic!("Attempted to get a BOOL from a non-bool value");
+    }
+}
+
+/// Either positional params (bound in order with `?1`, `?2`, ...) or named params
+/// (bound by `:name`/`@name`/`$name`), matching rusqlite's own unified params model.
+enum ParamsKind {
+    Positional(Vec<DatabaseParam>),
+    Named(Vec<(&'static str, DatabaseParam)>),
 }
 
 pub struct DatabaseParams {
-    params: Vec<DatabaseParam>
+    kind: ParamsKind
 }
 
 impl DatabaseParams {
-    fn to_params(&self) -> ParamsFromIter<Vec<Value>> {
-        let params: Vec<Value> = self.params.iter().map(|x| x.to_sql()).collect();
-        params_from_iter(params)
+    pub fn empty() -> DatabaseParams { DatabaseParams { kind: ParamsKind::Positional(Vec::new()) } }
+    pub fn new(params: Vec<DatabaseParam>) -> DatabaseParams { DatabaseParams { kind: ParamsKind::Positional(params) } }
+    pub fn single(param: DatabaseParam) -> DatabaseParams { DatabaseParams { kind: ParamsKind::Positional(vec![param]) } }
+
+    /// Bind parameters by name (`:name`, `@name`, or `$name` in the query text)
+    /// instead of positionally, so statements don't depend on brittle argument order.
+    pub fn named(params: Vec<(&'static str, DatabaseParam)>) -> DatabaseParams {
+        DatabaseParams { kind: ParamsKind::Named(params) }
     }
 
-    pub fn empty() -> DatabaseParams { DatabaseParams { params: Vec::new() } }
-    pub fn new(params: Vec<DatabaseParam>) -> DatabaseParams { DatabaseParams { params } }
-    pub fn single(param: DatabaseParam) -> DatabaseParams { DatabaseParams { params: vec![param] } }
+    fn execute(&self, statement: &mut rusqlite::Statement) -> rusqlite::Result<usize> {
+        match &self.kind {
+            ParamsKind::Positional(params) => {
+                let values: Vec<Box<dyn ToSql>> = params.iter().map(|x| x.to_sql()).collect::<rusqlite::Result<_>>()?;
+                statement.execute(params_from_iter(values))
+            },
+            ParamsKind::Named(params) => {
+                let values: Vec<(&'static str, Box<dyn ToSql>)> = params.iter()
+                    .map(|(name, p)| p.to_sql().map(|v| (*name, v)))
+                    .collect::<rusqlite::Result<_>>()?;
+                let refs: Vec<(&str, &dyn ToSql)> = values.iter().map(|(name, v)| (*name, v.as_ref())).collect();
+                statement.execute(refs.as_slice())
+            }
+        }
+    }
+
+    fn query_map(
+        &self, statement: &mut rusqlite::Statement, column_count: usize
+    ) -> rusqlite::Result<Vec<Vec<DatabaseParam>>> {
+        match &self.kind {
+            ParamsKind::Positional(params) => {
+                let values: Vec<Box<dyn ToSql>> = params.iter().map(|x| x.to_sql()).collect::<rusqlite::Result<_>>()?;
+                let rows = statement.query_map(params_from_iter(values), |row| decode_row(row, column_count))?;
+                Ok(rows.filter_map(|x| x.ok()).collect())
+            },
+            ParamsKind::Named(params) => {
+                let values: Vec<(&'static str, Box<dyn ToSql>)> = params.iter()
+                    .map(|(name, p)| p.to_sql().map(|v| (*name, v)))
+                    .collect::<rusqlite::Result<_>>()?;
+                let refs: Vec<(&str, &dyn ToSql)> = values.iter().map(|(name, v)| (*name, v.as_ref())).collect();
+                let rows = statement.query_map(refs.as_slice(), |row| decode_row(row, column_count))?;
+                Ok(rows.filter_map(|x| x.ok()).collect())
+            }
+        }
+    }
 }
 
 pub struct Database {
-    _handle: JoinHandle<()>,
+    _handles: Vec<JoinHandle<()>>,
     datalink: DataLink
 }
 
+/// `DataLink` is cloneable and callers never see which physical thread serves a
+/// given call: in single-threaded mode both senders feed the same worker, in
+/// pooled mode `write_sender` feeds the writer and `read_sender` fans out to readers.
 #[derive(Clone)]
 pub struct DataLink {
-    task_sender: Sender<DatabaseTask>
+    write_sender: Sender<DatabaseTask>,
+    read_sender: Sender<DatabaseTask>,
 }
 
 impl DataLink {
-    pub fn new(task_sender: Sender<DatabaseTask>) -> DataLink {
-        DataLink { task_sender }
+    pub fn new(write_sender: Sender<DatabaseTask>, read_sender: Sender<DatabaseTask>) -> DataLink {
+        DataLink { write_sender, read_sender }
     }
 
-    pub fn execute(&self, query: &'static str, params: DatabaseParams) -> Result<(), ()> {
-        self.task_sender.send_blocking(DatabaseTask::Execute(query, params)).map_err(|_| ())
+    pub fn execute(&self, query: impl Into<Query>, params: DatabaseParams) -> Result<(), ()> {
+        self.write_sender.send_blocking(DatabaseTask::Execute(query.into(), params)).map_err(|_| ())
     }
 
-    pub async fn execute_and_wait(&self, query: &'static str, params: DatabaseParams) -> Result<(), ()> {
+    pub async fn execute_and_wait(&self, query: impl Into<Query>, params: DatabaseParams) -> Result<(), ()> {
         let (sender, receiver) = unbounded();
-        let _ = self.task_sender.send_blocking(DatabaseTask::WaitExecute(query, params, sender));
+        let _ = self.write_sender.send_blocking(DatabaseTask::WaitExecute(query.into(), params, sender));
         match receiver.recv().await {
             Ok(_) => Ok(()),
             Err(_) => Err(())
@@ -106,9 +241,9 @@ impl DataLink {
     }
 
     /// Execute function with receiver callback intended for insert commands (returns row id)
-    pub async fn insert(&self, query: &'static str, params: DatabaseParams) -> Option<usize> {
+    pub async fn insert(&self, query: impl Into<Query>, params: DatabaseParams) -> Option<usize> {
         let (sender, receiver) = unbounded();
-        let _ = self.task_sender.send_blocking(DatabaseTask::Insert(query, params, sender));
+        let _ = self.write_sender.send_blocking(DatabaseTask::Insert(query.into(), params, sender));
         let result = match receiver.recv().await {
             Ok(result) => result,
             Err(_) => return None
@@ -117,25 +252,25 @@ impl DataLink {
         match result { InsertMessage::Success(v) => Some(v), InsertMessage::Error => None }
     }
 
-    pub fn insert_stream(&self, query: &'static str, params: DatabaseParams) -> Receiver<InsertMessage> {
+    pub fn insert_stream(&self, query: impl Into<Query>, params: DatabaseParams) -> Receiver<InsertMessage> {
         let (sender, receiver) = unbounded();
-        let _ = self.task_sender.send_blocking(DatabaseTask::Insert(query, params, sender));
+        let _ = self.write_sender.send_blocking(DatabaseTask::Insert(query.into(), params, sender));
         receiver
     }
 
     /// Return a receiver that receives the rows
-    pub fn query_stream(&self, query: &'static str, params: DatabaseParams) -> Receiver<ItemStream> {
+    pub fn query_stream(&self, query: impl Into<Query>, params: DatabaseParams) -> Receiver<ItemStream> {
         let (sender, receiver) = unbounded();
-        let _ = self.task_sender.send_blocking(DatabaseTask::Query(query, params, sender));
+        let _ = self.read_sender.send_blocking(DatabaseTask::Query(query.into(), params, sender));
         receiver
     }
 
     /// Collect all results, then proceed
     pub async fn query_map(
-        &self, query: &'static str, params: DatabaseParams
+        &self, query: impl Into<Query>, params: DatabaseParams
     ) -> Result<Vec<Vec<DatabaseParam>>, ResonateError> {
         let (sender, receiver) = unbounded();
-        let _ = self.task_sender.send_blocking(DatabaseTask::Query(query, params, sender));
+        let _ = self.read_sender.send_blocking(DatabaseTask::Query(query.into(), params, sender));
 
         let mut values = Vec::new();
         let mut error = false;
@@ -149,19 +284,189 @@ impl DataLink {
 
         match error {
             false => Ok(values),
-            true => Err(ResonateError::GenericError)
+            true => Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+        }
+    }
+
+    /// Like [`DataLink::query_map`], but decodes each row into `T` via [`FromRow`] on
+    /// the worker thread, before it crosses the channel, instead of handing back
+    /// untyped columns for the caller to decode itself.
+    pub async fn query_as<T: FromRow + Send + 'static>(
+        &self, query: impl Into<Query>, params: DatabaseParams
+    ) -> Result<Vec<T>, ResonateError> {
+        let (sender, receiver) = unbounded();
+        let _ = self.read_sender.send_blocking(DatabaseTask::TypedQuery(query.into(), params, row_decoder::<T>(), sender));
+
+        let mut values = Vec::new();
+        let mut error = false;
+        while let Ok(item) = receiver.recv().await {
+            match item {
+                TypedItemStream::End => break,
+                TypedItemStream::Error => { error = true; break },
+                TypedItemStream::Value(v) => values.push(downcast_row::<T>(v))
+            };
+        }
+
+        match error {
+            false => Ok(values),
+            true => Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+        }
+    }
+
+    /// Like [`DataLink::query_stream`], but decodes each row into `T` via [`FromRow`]
+    /// on the worker thread, before it crosses the channel, instead of handing back
+    /// untyped columns for the caller to decode itself.
+    pub fn query_stream_as<T: FromRow + Send + 'static>(&self, query: impl Into<Query>, params: DatabaseParams) -> TypedStream<T> {
+        let (sender, receiver) = unbounded();
+        let _ = self.read_sender.send_blocking(DatabaseTask::TypedQuery(query.into(), params, row_decoder::<T>(), sender));
+        TypedStream { receiver, _marker: std::marker::PhantomData }
+    }
+
+    /// Run every `(query, params)` pair atomically inside a single transaction.
+    /// Rolls back and returns `Err(())` if any statement fails; otherwise returns
+    /// the affected-row count of each statement, in order.
+    pub async fn transaction<Q: Into<Query>>(&self, ops: Vec<(Q, DatabaseParams)>) -> Result<Vec<usize>, ()> {
+        self.run_transaction(ops, false).await
+    }
+
+    /// Like [`DataLink::transaction`], but issues `BEGIN IMMEDIATE` so the write
+    /// lock is acquired up front instead of on the transaction's first write.
+    pub async fn transaction_immediate<Q: Into<Query>>(&self, ops: Vec<(Q, DatabaseParams)>) -> Result<Vec<usize>, ()> {
+        self.run_transaction(ops, true).await
+    }
+
+    async fn run_transaction<Q: Into<Query>>(&self, ops: Vec<(Q, DatabaseParams)>, immediate: bool) -> Result<Vec<usize>, ()> {
+        let ops = ops.into_iter().map(|(query, params)| (query.into(), params)).collect();
+        let (sender, receiver) = unbounded();
+        let _ = self.write_sender.send_blocking(DatabaseTask::Transaction(ops, immediate, sender));
+        match receiver.recv().await {
+            Ok(result) => result,
+            Err(_) => Err(())
+        }
+    }
+
+    /// Run a ranked FTS5 `MATCH` query against `table`, returning the first page
+    /// of results and a [`SearchCursor`] for fetching subsequent pages.
+    pub async fn search(&self, table: &'static str, query: String, page_size: usize) -> (Vec<Vec<DatabaseParam>>, SearchCursor) {
+        let (sender, receiver) = unbounded();
+        let _ = self.read_sender.send_blocking(DatabaseTask::Search(table, query, page_size, sender));
+
+        let (cursor_id, first_page) = match receiver.recv().await {
+            Ok(result) => result,
+            Err(_) => (0, Vec::new())
+        };
+
+        let exhausted = first_page.len() < page_size;
+        (first_page, SearchCursor { datalink: self.clone(), cursor_id, exhausted })
+    }
+}
+
+/// Receiver of rows already decoded into `T` on the worker thread, returned by
+/// [`DataLink::query_stream_as`].
+pub struct TypedStream<T> {
+    receiver: Receiver<TypedItemStream>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Send + 'static> TypedStream<T> {
+    /// Return the next already-decoded row, or `None` once the stream has ended.
+    pub async fn recv(&self) -> Option<Result<T, ResonateError>> {
+        match self.receiver.recv().await {
+            Ok(TypedItemStream::Value(v)) => Some(Ok(downcast_row::<T>(v))),
+            Ok(TypedItemStream::Error) => Some(Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))),
+            Ok(TypedItemStream::End) | Err(_) => None,
         }
     }
 }
 
+/// Stateful pagination handle for an FTS5 search started via [`DataLink::search`].
+/// All offset bookkeeping lives on the worker thread; this is just an id.
+pub struct SearchCursor {
+    datalink: DataLink,
+    cursor_id: CursorId,
+    exhausted: bool,
+}
+
+impl SearchCursor {
+    /// Fetch the next page, or `None` once the search is exhausted.
+    pub async fn next_page(&mut self) -> Option<Vec<Vec<DatabaseParam>>> {
+        if self.exhausted { return None; }
+
+        let (sender, receiver) = unbounded();
+        let _ = self.datalink.read_sender.send_blocking(DatabaseTask::SearchNext(self.cursor_id, sender));
+
+        match receiver.recv().await {
+            Ok(Some(page)) => Some(page),
+            _ => {
+                self.exhausted = true;
+                None
+            }
+        }
+    }
+}
+
+impl Drop for SearchCursor {
+    /// Evict this cursor's pagination state if it's dropped before running out of
+    /// pages (e.g. the caller only wanted the first page), so the worker's cursor
+    /// table doesn't grow unboundedly under sustained search traffic.
+    fn drop(&mut self) {
+        if self.exhausted { return; }
+        let _ = self.datalink.read_sender.send_blocking(DatabaseTask::SearchDrop(self.cursor_id));
+    }
+}
+
+/// rusqlite's own default prepared-statement cache capacity.
+const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 16;
+
 impl Database {
     pub fn new(root_dir: PathBuf) -> Database {
+        Self::new_with_cache_capacity(root_dir, DEFAULT_STATEMENT_CACHE_CAPACITY)
+    }
+
+    /// Like [`Database::new`], but configures the size of the worker connection's
+    /// prepared-statement LRU cache instead of taking rusqlite's default.
+    pub fn new_with_cache_capacity(root_dir: PathBuf, capacity: usize) -> Database {
 
         let (task_sender, task_receiver) = unbounded();
 
         Database {
-            _handle: spawn(move || database_thread(root_dir, task_receiver)),
-            datalink: DataLink::new(task_sender)
+            _handles: vec![spawn(move || database_thread(root_dir, capacity, task_receiver))],
+            datalink: DataLink::new(task_sender.clone(), task_sender)
+        }
+    }
+
+    /// Like [`Database::new`], but splits the single worker into a dedicated
+    /// writer thread (its connection opened in WAL mode) plus `readers` reader
+    /// threads, each with their own read-only connection to the same file.
+    /// Writes (`Execute`/`Insert`/`Transaction`) go to the writer; `Query`/`Search`
+    /// fan out across the readers, which WAL mode lets run concurrently with writes.
+    pub fn new_pooled(root_dir: PathBuf, readers: usize) -> Database {
+
+        let (write_sender, write_receiver) = unbounded();
+        let (read_sender, read_receiver) = unbounded();
+        let cursor_store = Arc::new(Mutex::new(CursorStore::new()));
+
+        let mut handles = Vec::with_capacity(readers + 1);
+
+        // Open the writer's connection here, on the caller's thread, before spawning
+        // any reader: opening in WAL mode creates `data.db` if it doesn't exist yet,
+        // so this guarantees the file is already there by the time a reader tries to
+        // open it read-only, instead of racing the writer thread's first open.
+        let writer_connection = open_writer_connection(&root_dir, DEFAULT_STATEMENT_CACHE_CAPACITY);
+        handles.push(spawn(move || writer_thread(writer_connection, write_receiver)));
+
+        for _ in 0..readers {
+            let reader_root = root_dir.clone();
+            let reader_receiver = read_receiver.clone();
+            let reader_cursors = cursor_store.clone();
+            handles.push(spawn(move || {
+                reader_thread(reader_root, DEFAULT_STATEMENT_CACHE_CAPACITY, reader_receiver, reader_cursors)
+            }));
+        }
+
+        Database {
+            _handles: handles,
+            datalink: DataLink::new(write_sender, read_sender)
         }
     }
 
@@ -170,86 +475,373 @@ impl Database {
     }
 }
 
-fn database_thread(root_dir: PathBuf, task_receiver: Receiver<DatabaseTask>) {
+/// Pagination state for live FTS5 searches, keyed by cursor id.
+struct CursorStore {
+    next_id: CursorId,
+    cursors: HashMap<CursorId, (&'static str, String, usize, usize)>,
+}
+
+impl CursorStore {
+    fn new() -> CursorStore {
+        CursorStore { next_id: 0, cursors: HashMap::new() }
+    }
+
+    fn alloc(&mut self) -> CursorId {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn insert(&mut self, id: CursorId, table: &'static str, match_expr: String, page_size: usize, offset: usize) {
+        self.cursors.insert(id, (table, match_expr, page_size, offset));
+    }
+
+    fn get(&self, id: CursorId) -> Option<(&'static str, String, usize, usize)> {
+        self.cursors.get(&id).cloned()
+    }
+
+    fn remove(&mut self, id: CursorId) {
+        self.cursors.remove(&id);
+    }
+}
+
+fn open_writer_connection(root_dir: &Path, cache_capacity: usize) -> Option<Connection> {
+    let connection = Connection::open(root_dir.join("data.db")).ok()?;
+    let _: String = connection.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0)).ok()?;
+    connection.set_prepared_statement_cache_capacity(cache_capacity);
+    Some(connection)
+}
+
+fn open_reader_connection(root_dir: &Path, cache_capacity: usize) -> Option<Connection> {
+    let connection = Connection::open_with_flags(
+        root_dir.join("data.db"), OpenFlags::SQLITE_OPEN_READ_ONLY
+    ).ok()?;
+    connection.set_prepared_statement_cache_capacity(cache_capacity);
+    Some(connection)
+}
+
+fn handle_execute(connection: &Connection, query: &str, params: DatabaseParams) {
+    if let Ok(mut statement) = connection.prepare_cached(query) {
+        let _ = params.execute(&mut statement);
+    }
+}
+
+fn handle_wait_execute(connection: &Connection, query: &str, params: DatabaseParams, sender: Sender<()>) {
+    if let Ok(mut statement) = connection.prepare_cached(query) {
+        let _ = params.execute(&mut statement);
+    }
+    let _ = sender.send_blocking(());
+}
+
+fn handle_insert(connection: &Connection, query: &str, params: DatabaseParams, sender: Sender<InsertMessage>) {
+    if let Ok(mut statement) = connection.prepare_cached(query) {
+        let _ = params.execute(&mut statement);
+        let _ = sender.send_blocking(InsertMessage::Success(connection.last_insert_rowid() as usize));
+    } else {
+        let _ = sender.send_blocking(InsertMessage::Error);
+    }
+}
+
+fn handle_query(connection: &Connection, query: &str, params: DatabaseParams, sender: Sender<ItemStream>) {
+    let rows = match query_rows(connection, query, &params) {
+        Ok(rows) => rows,
+        Err(_) => {
+            let _ = sender.send_blocking(ItemStream::Error);
+            return
+        }
+    };
+
+    for row in rows {
+        let _ = sender.send_blocking(ItemStream::Value(row));
+    }
+    let _ = sender.send_blocking(ItemStream::End);
+}
+
+/// Builds the [`RowDecoder`] behind [`DataLink::query_as`]/[`DataLink::query_stream_as`]:
+/// decodes a row into `T` via [`FromRow`] and boxes it as `Any` to cross the channel.
+fn row_decoder<T: FromRow + Send + 'static>() -> RowDecoder {
+    Box::new(|row| T::from_row(row).map(|v| Box::new(v) as Box<dyn Any + Send>))
+}
+
+/// Downcasts a [`TypedItemStream::Value`] back to the concrete type it was built
+/// from by [`row_decoder`]. Always succeeds: the box is only ever constructed for `T`.
+fn downcast_row<T: 'static>(value: Box<dyn Any + Send>) -> T {
+    *value.downcast::<T>().unwrap_or_else(|_| unreachable!("RowDecoder always produces the requested type"))
+}
+
+fn handle_typed_query(
+    connection: &Connection, query: &str, params: DatabaseParams, decoder: &RowDecoder, sender: Sender<TypedItemStream>
+) {
+    let rows = match query_rows(connection, query, &params) {
+        Ok(rows) => rows,
+        Err(_) => {
+            let _ = sender.send_blocking(TypedItemStream::Error);
+            return
+        }
+    };
+
+    for row in rows {
+        match decoder(&RowView::new(&row)) {
+            Ok(value) => { let _ = sender.send_blocking(TypedItemStream::Value(value)); },
+            Err(_) => {
+                let _ = sender.send_blocking(TypedItemStream::Error);
+                return
+            }
+        }
+    }
+    let _ = sender.send_blocking(TypedItemStream::End);
+}
+
+fn handle_transaction(
+    connection: &mut Connection, ops: Vec<(Query, DatabaseParams)>, immediate: bool, sender: Sender<Result<Vec<usize>, ()>>
+) {
+    let result = (|| -> Result<Vec<usize>, ()> {
+        let txn = if immediate {
+            connection.transaction_with_behavior(TransactionBehavior::Immediate).map_err(|_| ())?
+        } else {
+            connection.transaction().map_err(|_| ())?
+        };
+
+        let mut affected = Vec::with_capacity(ops.len());
+        for (query, params) in ops {
+            let mut statement = txn.prepare_cached(&query).map_err(|_| ())?;
+            affected.push(params.execute(&mut statement).map_err(|_| ())?);
+        }
+
+        txn.commit().map_err(|_| ())?;
+        Ok(affected)
+    })();
+
+    let _ = sender.send_blocking(result);
+}
+
+/// Run the first-page FTS5 `MATCH` query. Split out from `handle_search` so pooled
+/// readers can run it without holding the cursor-table lock.
+fn run_search_query(connection: &Connection, table: &'static str, match_expr: &str, page_size: usize) -> Vec<Vec<DatabaseParam>> {
+    let query = format!("SELECT * FROM {table} WHERE {table} MATCH ?1 ORDER BY rank LIMIT ?2");
+    let params = DatabaseParams::new(vec![
+        DatabaseParam::String(match_expr.to_owned()),
+        DatabaseParam::Usize(page_size),
+    ]);
+    query_rows(connection, &query, &params).unwrap_or_default()
+}
+
+/// Run a subsequent-page FTS5 `MATCH` query. Split out from `handle_search_next` so
+/// pooled readers can run it without holding the cursor-table lock.
+fn run_search_next_query(
+    connection: &Connection, table: &'static str, match_expr: &str, page_size: usize, offset: usize
+) -> Vec<Vec<DatabaseParam>> {
+    let query = format!("SELECT * FROM {table} WHERE {table} MATCH ?1 ORDER BY rank LIMIT ?2 OFFSET ?3");
+    let params = DatabaseParams::new(vec![
+        DatabaseParam::String(match_expr.to_owned()),
+        DatabaseParam::Usize(page_size),
+        DatabaseParam::Usize(offset),
+    ]);
+    query_rows(connection, &query, &params).unwrap_or_default()
+}
+
+fn handle_search(
+    connection: &Connection, table: &'static str, match_expr: String, page_size: usize,
+    sender: Sender<(CursorId, Vec<Vec<DatabaseParam>>)>, cursors: &mut CursorStore
+) {
+    let rows = run_search_query(connection, table, &match_expr, page_size);
+    let cursor_id = cursors.alloc();
+
+    if rows.len() == page_size {
+        cursors.insert(cursor_id, table, match_expr, page_size, page_size);
+    }
+
+    let _ = sender.send_blocking((cursor_id, rows));
+}
+
+fn handle_search_next(
+    connection: &Connection, cursor_id: CursorId, sender: Sender<Option<Vec<Vec<DatabaseParam>>>>, cursors: &mut CursorStore
+) {
+    let Some((table, match_expr, page_size, offset)) = cursors.get(cursor_id) else {
+        let _ = sender.send_blocking(None);
+        return
+    };
+
+    let rows = run_search_next_query(connection, table, &match_expr, page_size, offset);
+
+    if rows.is_empty() {
+        cursors.remove(cursor_id);
+        let _ = sender.send_blocking(None);
+    } else {
+        cursors.insert(cursor_id, table, match_expr, page_size, offset + page_size);
+        let _ = sender.send_blocking(Some(rows));
+    }
+}
+
+/// Pooled-reader version of `handle_search`: only the cursor-table bookkeeping runs
+/// under `cursors`'s lock, so the FTS5 query itself doesn't serialize every reader.
+fn handle_search_pooled(
+    connection: &Connection, table: &'static str, match_expr: String, page_size: usize,
+    sender: Sender<(CursorId, Vec<Vec<DatabaseParam>>)>, cursors: &Arc<Mutex<CursorStore>>
+) {
+    let rows = run_search_query(connection, table, &match_expr, page_size);
+
+    let cursor_id = {
+        let mut cursors = cursors.lock().unwrap();
+        let id = cursors.alloc();
+        if rows.len() == page_size {
+            cursors.insert(id, table, match_expr, page_size, page_size);
+        }
+        id
+    };
+
+    let _ = sender.send_blocking((cursor_id, rows));
+}
+
+/// Pooled-reader version of `handle_search_next`: only the cursor-table bookkeeping
+/// runs under `cursors`'s lock, so the FTS5 query itself doesn't serialize every reader.
+fn handle_search_next_pooled(
+    connection: &Connection, cursor_id: CursorId, sender: Sender<Option<Vec<Vec<DatabaseParam>>>>, cursors: &Arc<Mutex<CursorStore>>
+) {
+    let state = cursors.lock().unwrap().get(cursor_id);
+    let Some((table, match_expr, page_size, offset)) = state else {
+        let _ = sender.send_blocking(None);
+        return
+    };
+
+    let rows = run_search_next_query(connection, table, &match_expr, page_size, offset);
+
+    if rows.is_empty() {
+        cursors.lock().unwrap().remove(cursor_id);
+        let _ = sender.send_blocking(None);
+    } else {
+        cursors.lock().unwrap().insert(cursor_id, table, match_expr, page_size, offset + page_size);
+        let _ = sender.send_blocking(Some(rows));
+    }
+}
+
+/// Evict a cursor's pagination state without running a query, used for both the
+/// single-threaded worker and pooled readers when a [`SearchCursor`] is dropped.
+fn handle_search_drop(cursor_id: CursorId, cursors: &mut CursorStore) {
+    cursors.remove(cursor_id);
+}
+
+/// Single-threaded worker backing [`Database::new`]: one connection serves every
+/// task kind in arrival order.
+fn database_thread(root_dir: PathBuf, cache_capacity: usize, task_receiver: Receiver<DatabaseTask>) {
 
-    let connection = match Connection::open(root_dir.join("data.db")) {
-        Ok(connection) => connection,
-        Err(_) => return
+    let mut connection = match open_writer_connection(&root_dir, cache_capacity) {
+        Some(connection) => connection,
+        None => return
     };
 
-    'mainloop: loop {
+    let mut cursors = CursorStore::new();
+
+    loop {
         let current_task = match task_receiver.recv_blocking() {
             Ok(task) => task,
             Err(_) => return
         };
 
         match current_task {
-            DatabaseTask::Execute(query, params) => {
-                if let Ok(mut statement) = connection.prepare(query) {
-                    let _ = statement.execute(params.to_params());
-                }
-            },
-            DatabaseTask::WaitExecute(query, params, sender) => {
-                if let Ok(mut statement) = connection.prepare(query) {
-                    let _ = statement.execute(params.to_params());
-                    let _ = sender.send_blocking(());
-                } else {
-                    let _ = sender.send_blocking(());
-                }
-            },
-            DatabaseTask::Insert(query, params, sender) => {
-                if let Ok(mut statement) = connection.prepare(query) {
-                    let _ = statement.execute(params.to_params());
-                    let _ = sender.send_blocking(InsertMessage::Success(connection.last_insert_rowid() as usize));
-                } else {
-                    let _ = sender.send_blocking(InsertMessage::Error);
-                }
-            }
-            DatabaseTask::Query(query, params, sender) => {
-                let mut statement = match connection.prepare(query) {
-                    Ok(statement) => statement,
-                    Err(_) => {
-                        let _ = sender.send_blocking(ItemStream::Error);
-                        continue
-                    }
-                };
-
-                let column_count = statement.column_count();
-                let rows = match statement.query_map(params.to_params(), |row| {
-                    let mut values = Vec::new();
-
-                    'inner: for idx in 0..column_count {
-                        let value = match row.get_ref(idx) {
-                            Ok(value) => value,
-                            Err(_) => continue 'inner
-                        };
-
-                        let value = match value {
-                            ValueRef::Null => DatabaseParam::Null,
-                            ValueRef::Integer(i) => DatabaseParam::Usize(i as usize),
-                            ValueRef::Real(f) => DatabaseParam::F64(f),
-                            ValueRef::Text(s) => DatabaseParam::String(String::from_utf8_lossy(s).into_owned()),
-                            ValueRef::Blob(_) => DatabaseParam::Null,
-                        };
-
-                        values.push(value);
-                    }
-
-                    if column_count == values.len() { Ok(values) }
-                    else { Err(rusqlite::Error::QueryReturnedNoRows) }
-                }) {
-                    Ok(rows) => rows.filter_map(|x| x.ok()).collect::<Vec<Vec<DatabaseParam>>>(),
-                    Err(_) => {
-                        let _ = sender.send_blocking(ItemStream::Error);
-                        continue 'mainloop
-                    }
-                };
-
-                for row in rows {
-                    let _ = sender.send_blocking(ItemStream::Value(row));
-                }
-                let _ = sender.send_blocking(ItemStream::End);
-            }
+            DatabaseTask::Execute(query, params) => handle_execute(&connection, &query, params),
+            DatabaseTask::WaitExecute(query, params, sender) => handle_wait_execute(&connection, &query, params, sender),
+            DatabaseTask::Insert(query, params, sender) => handle_insert(&connection, &query, params, sender),
+            DatabaseTask::Query(query, params, sender) => handle_query(&connection, &query, params, sender),
+            DatabaseTask::TypedQuery(query, params, decoder, sender) =>
+                handle_typed_query(&connection, &query, params, &decoder, sender),
+            DatabaseTask::Transaction(ops, immediate, sender) => handle_transaction(&mut connection, ops, immediate, sender),
+            DatabaseTask::Search(table, match_expr, page_size, sender) =>
+                handle_search(&connection, table, match_expr, page_size, sender, &mut cursors),
+            DatabaseTask::SearchNext(cursor_id, sender) => handle_search_next(&connection, cursor_id, sender, &mut cursors),
+            DatabaseTask::SearchDrop(cursor_id) => handle_search_drop(cursor_id, &mut cursors),
+        }
+    }
+}
+
+/// Dedicated writer used by [`Database::new_pooled`]. `connection` is opened by the
+/// caller of [`Database::new_pooled`] before any reader thread is spawned, so the
+/// database file is guaranteed to exist (in WAL mode) before readers try to open it.
+fn writer_thread(connection: Option<Connection>, task_receiver: Receiver<DatabaseTask>) {
+
+    let mut connection = match connection {
+        Some(connection) => connection,
+        None => return
+    };
+
+    loop {
+        let current_task = match task_receiver.recv_blocking() {
+            Ok(task) => task,
+            Err(_) => return
+        };
+
+        match current_task {
+            DatabaseTask::Execute(query, params) => handle_execute(&connection, &query, params),
+            DatabaseTask::WaitExecute(query, params, sender) => handle_wait_execute(&connection, &query, params, sender),
+            DatabaseTask::Insert(query, params, sender) => handle_insert(&connection, &query, params, sender),
+            DatabaseTask::Transaction(ops, immediate, sender) => handle_transaction(&mut connection, ops, immediate, sender),
+            _ => unreachable!("DataLink never routes read tasks to the writer")
         }
     }
 }
+
+/// One of `readers` reader threads used by [`Database::new_pooled`], each with its
+/// own read-only connection. `task_receiver` is a shared MPMC receiver, so readers
+/// work-steal tasks as they become idle; `cursors` is shared so a search's pages
+/// can be served by whichever reader happens to pick up the next request.
+fn reader_thread(root_dir: PathBuf, cache_capacity: usize, task_receiver: Receiver<DatabaseTask>, cursors: Arc<Mutex<CursorStore>>) {
+
+    let connection = match open_reader_connection(&root_dir, cache_capacity) {
+        Some(connection) => connection,
+        None => return
+    };
+
+    loop {
+        let current_task = match task_receiver.recv_blocking() {
+            Ok(task) => task,
+            Err(_) => return
+        };
+
+        match current_task {
+            DatabaseTask::Query(query, params, sender) => handle_query(&connection, &query, params, sender),
+            DatabaseTask::TypedQuery(query, params, decoder, sender) =>
+                handle_typed_query(&connection, &query, params, &decoder, sender),
+            DatabaseTask::Search(table, match_expr, page_size, sender) =>
+                handle_search_pooled(&connection, table, match_expr, page_size, sender, &cursors),
+            DatabaseTask::SearchNext(cursor_id, sender) => handle_search_next_pooled(&connection, cursor_id, sender, &cursors),
+            DatabaseTask::SearchDrop(cursor_id) => handle_search_drop(cursor_id, &mut cursors.lock().unwrap()),
+            _ => unreachable!("DataLink never routes write tasks to a reader")
+        }
+    }
+}
+
+/// Decode one row's columns into `Vec<DatabaseParam>`, shared by every query path.
+fn decode_row(row: &rusqlite::Row, column_count: usize) -> rusqlite::Result<Vec<DatabaseParam>> {
+    let mut values = Vec::new();
+
+    'inner: for idx in 0..column_count {
+        let value = match row.get_ref(idx) {
+            Ok(value) => value,
+            Err(_) => continue 'inner
+        };
+
+        let value = match value {
+            ValueRef::Null => DatabaseParam::Null,
+            // Negative columns can't fit `Usize` without wrapping to a huge positive
+            // value, so keep them as `I64` instead of silently corrupting them.
+            ValueRef::Integer(i) if i < 0 => DatabaseParam::I64(i),
+            ValueRef::Integer(i) => DatabaseParam::Usize(i as usize),
+            ValueRef::Real(f) => DatabaseParam::F64(f),
+            ValueRef::Text(s) => DatabaseParam::String(String::from_utf8_lossy(s).into_owned()),
+            ValueRef::Blob(b) => DatabaseParam::Blob(b.to_vec()),
+        };
+
+        values.push(value);
+    }
+
+    if column_count == values.len() { Ok(values) }
+    else { Err(rusqlite::Error::QueryReturnedNoRows) }
+}
+
+/// Run `query` against `connection` and decode every returned row into
+/// `Vec<DatabaseParam>`. Shared by `DatabaseTask::Query` and the FTS5 search tasks.
+fn query_rows(connection: &Connection, query: &str, params: &DatabaseParams) -> rusqlite::Result<Vec<Vec<DatabaseParam>>> {
+    let mut statement = connection.prepare_cached(query)?;
+    let column_count = statement.column_count();
+    params.query_map(&mut statement, column_count)
+}
@@ -3,7 +3,7 @@ use async_channel::SendError;
 use async_channel::RecvError;
 use async_channel::TryRecvError;
 
-pub type Res<T> = Result<T, Error>;
+pub type Res<T> = Result<T, ResonateError>;
 type StdIoError = std::io::Error;
 
 macro_rules! error_enum {
@@ -37,6 +37,9 @@ pub enum ChannelError {
     ChannelEmpty
 }
 
+#[derive(Debug, Clone)]
+pub struct GenericError;
+
 impl<T> From<SendError<T>> for ChannelError {
     fn from(_: SendError<T>) -> ChannelError {
         ChannelError::ChannelDead
@@ -59,8 +62,9 @@ impl From<TryRecvError> for ChannelError {
 }
 
 error_enum! {
-    pub enum Error {
+    pub enum ResonateError {
         StdIoError,
         ChannelError,
+        GenericError,
     }
 }
@@ -0,0 +1,108 @@
+use super::database::DatabaseParam;
+use super::error::GenericError;
+use super::error::ResonateError;
+
+/// Read-only view over one decoded row, indexed positionally like the
+/// `Vec<DatabaseParam>` it is built from.
+pub struct RowView<'a> {
+    columns: &'a [DatabaseParam]
+}
+
+impl<'a> RowView<'a> {
+    pub fn new(columns: &'a [DatabaseParam]) -> RowView<'a> {
+        RowView { columns }
+    }
+
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Result<&DatabaseParam, ResonateError> {
+        self.columns.get(index).ok_or_else(|| ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+    }
+}
+
+/// Decode a single column into a concrete type.
+pub trait FromColumn: Sized {
+    fn from_column(param: &DatabaseParam) -> Result<Self, ResonateError>;
+}
+
+macro_rules! impl_from_column {
+    ($ty:ty, $variant:ident) => {
+        impl FromColumn for $ty {
+            fn from_column(param: &DatabaseParam) -> Result<Self, ResonateError> {
+                if let DatabaseParam::$variant(v) = param { return Ok(v.clone()); }
+                Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+            }
+        }
+    };
+}
+
+impl_from_column!(usize, Usize);
+impl_from_column!(String, String);
+impl_from_column!(f64, F64);
+impl_from_column!(Vec<u8>, Blob);
+
+// `i64`/`bool` values are bound as `I64`/`Bool` through `DatabaseParam`, but decoded
+// query results never carry `Bool` and store non-negative integers as `Usize` (SQLite
+// has no separate integer width or boolean type), so these also accept that variant.
+impl FromColumn for i64 {
+    fn from_column(param: &DatabaseParam) -> Result<Self, ResonateError> {
+        match param {
+            DatabaseParam::I64(v) => Ok(*v),
+            DatabaseParam::Usize(v) => Ok(*v as i64),
+            _ => Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+        }
+    }
+}
+
+impl FromColumn for bool {
+    fn from_column(param: &DatabaseParam) -> Result<Self, ResonateError> {
+        match param {
+            DatabaseParam::Bool(v) => Ok(*v),
+            DatabaseParam::Usize(v) => Ok(*v != 0),
+            _ => Err(ResonateError::GenericError(std::sync::Arc::new(GenericError)))
+        }
+    }
+}
+
+impl<T: FromColumn> FromColumn for Option<T> {
+    fn from_column(param: &DatabaseParam) -> Result<Self, ResonateError> {
+        if let DatabaseParam::Null = param { return Ok(None); }
+        T::from_column(param).map(Some)
+    }
+}
+
+/// Decode a whole row into a concrete type, analogous to rusqlite's own
+/// row-mapping closures but backed by the decoded `RowView` the worker
+/// thread already produces.
+pub trait FromRow: Sized {
+    fn from_row(row: &RowView) -> Result<Self, ResonateError>;
+}
+
+macro_rules! impl_from_row_tuple {
+    ($count:expr; $($idx:tt => $ty:ident),+) => {
+        impl<$($ty: FromColumn),+> FromRow for ($($ty,)+) {
+            fn from_row(row: &RowView) -> Result<Self, ResonateError> {
+                Ok(($($ty::from_column(row.get($idx)?)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_tuple!(1; 0 => A);
+impl_from_row_tuple!(2; 0 => A, 1 => B);
+impl_from_row_tuple!(3; 0 => A, 1 => B, 2 => C);
+impl_from_row_tuple!(4; 0 => A, 1 => B, 2 => C, 3 => D);
+impl_from_row_tuple!(5; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_from_row_tuple!(6; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_from_row_tuple!(7; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_from_row_tuple!(8; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+impl_from_row_tuple!(9; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I);
+impl_from_row_tuple!(10; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J);
+impl_from_row_tuple!(11; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K);
+impl_from_row_tuple!(12; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K, 11 => L);
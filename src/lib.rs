@@ -0,0 +1,6 @@
+pub mod database;
+pub mod error;
+pub mod from_row;
+
+pub use database::{Database, DataLink, DatabaseParam, DatabaseParams};
+pub use error::ResonateError;